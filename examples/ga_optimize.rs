@@ -23,7 +23,7 @@ struct SmaParams {
 
 impl Genome for SmaParams {
     fn random(rng: &mut dyn rand::RngCore) -> Self {
-        let mut fast = rng.gen_range(5..=40);
+        let fast = rng.gen_range(5..=40);
         let mut slow = rng.gen_range(20..=160);
         if slow <= fast {
             slow = fast + 5;
@@ -123,6 +123,7 @@ fn main() -> Result<()> {
         elitism: 4,
         generations: 20,
         tournament_size: 3,
+        ..GeneticOptimizerConfig::default()
     };
 
     let optimizer = GeneticOptimizer::new(config, SyntheticEvaluator);
@@ -24,6 +24,7 @@ fn main() {
             max_position_size_pct: 0.05,
             stop_loss_pct: 0.02,
             take_profit_pct: 0.04,
+            ..RiskConfig::default()
         },
         100_000.0,
     );
@@ -6,7 +6,6 @@ use std::collections::HashMap;
 ///
 /// This example demonstrates the basic functionality available in the current library.
 /// It shows how to create positions, orders, and use the risk manager.
-
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("🚀 Simple Hyperliquid Backtester Example");
     println!("========================================\n");
@@ -38,6 +37,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         max_position_size_pct: 0.1, // 10% of portfolio
         stop_loss_pct: 0.05,        // 5% stop loss
         take_profit_pct: 0.1,       // 10% take profit
+        ..RiskConfig::default()
     };
     
     let risk_manager = RiskManager::new(risk_config, 10000.0); // $10,000 portfolio
@@ -83,6 +83,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Create a funding payment
     println!("\n💰 Creating a funding payment...");
     let funding_payment = FundingPayment {
+        symbol: position.symbol.clone(),
         timestamp: Utc::now().with_timezone(&FixedOffset::east_opt(0).unwrap()),
         position_size: position.size,
         funding_rate: 0.0001, // 0.01% funding rate
@@ -2,7 +2,7 @@
 // It doesn't depend on the existing codebase
 
 use std::collections::HashMap;
-use chrono::{DateTime, FixedOffset, TimeZone, Utc};
+use chrono::{DateTime, FixedOffset, Utc};
 
 // Position information across all trading modes
 #[derive(Debug, Clone)]
@@ -334,7 +334,7 @@ fn main() {
     println!("Unified Data Structures Example");
     println!("===============================");
     
-    let now = Utc::now().with_timezone(&FixedOffset::east(0));
+    let now = Utc::now().with_timezone(&FixedOffset::east_opt(0).unwrap());
     
     // Create a position
     let mut position = Position::new("BTC", 1.0, 50000.0, 51000.0, now);
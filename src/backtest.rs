@@ -3,6 +3,8 @@ use chrono::{DateTime, FixedOffset};
 /// Minimal representation of a funding payment used in tests and simplified workflows.
 #[derive(Debug, Clone, PartialEq)]
 pub struct FundingPayment {
+    /// Asset symbol the payment applies to.
+    pub symbol: String,
     /// Timestamp of the payment.
     pub timestamp: DateTime<FixedOffset>,
     /// Position size in contracts at the time of the payment.
@@ -14,3 +16,26 @@ pub struct FundingPayment {
     /// Mark price when the payment was settled.
     pub mark_price: f64,
 }
+
+impl FundingPayment {
+    /// Create a payment for a position, deriving the amount from the funding rate.
+    ///
+    /// With a positive rate longs pay shorts, so the amount is
+    /// `-position_size * mark_price * funding_rate`.
+    pub fn new(
+        symbol: &str,
+        timestamp: DateTime<FixedOffset>,
+        position_size: f64,
+        funding_rate: f64,
+        mark_price: f64,
+    ) -> Self {
+        Self {
+            symbol: symbol.to_string(),
+            timestamp,
+            position_size,
+            funding_rate,
+            payment_amount: -position_size * mark_price * funding_rate,
+            mark_price,
+        }
+    }
+}
@@ -1,8 +1,9 @@
 //! Minimal Hyperliquid backtesting toolkit.
 //!
 //! This crate provides just enough building blocks to run lightweight experiments
-//! in unit tests: a [`Position`] type, simple order requests, a [`FundingPayment`]
-//! structure and a very small [`RiskManager`]. The implementation intentionally
+//! in unit tests: a [`Position`](unified_data::Position) type, simple order requests, a
+//! [`FundingPayment`](backtest::FundingPayment) structure and a very small
+//! [`RiskManager`](risk_manager::RiskManager). The implementation intentionally
 //! avoids external dependencies or complex behaviours so the library can compile
 //! quickly and remain easy to understand.
 
@@ -14,6 +15,7 @@ pub mod unified_data;
 #[cfg(test)]
 mod tests {
     mod basic;
+    mod optimization;
 }
 
 /// Convenient re-export of the most common items used when writing examples or tests.
@@ -21,6 +23,6 @@ pub mod prelude {
     pub use crate::backtest::FundingPayment;
     pub use crate::risk_manager::{RiskConfig, RiskError, RiskManager, RiskOrder};
     pub use crate::unified_data::{
-        OrderRequest, OrderResult, OrderSide, OrderType, Position, TimeInForce,
+        OrderRequest, OrderResult, OrderSide, OrderType, Position, RejectionReason, TimeInForce,
     };
 }
@@ -1,4 +1,5 @@
-use rand::RngCore;
+use rand::rngs::StdRng;
+use rand::{Rng, RngCore, SeedableRng};
 use std::{fmt, marker::PhantomData};
 
 /// Defines how candidate parameters behave within the genetic algorithm.
@@ -9,6 +10,29 @@ pub trait Genome: Clone + Send + Sync + Sized {
     /// Produce a mutated version of this candidate.
     fn mutate(&mut self, rng: &mut dyn RngCore);
 
+    /// Mutate the candidate with the given rate, where the rate is the expected number of
+    /// [`Genome::mutate`] applications. The default applies `mutate` once per whole unit
+    /// of `rate` and once more with probability equal to the fractional remainder. The rate
+    /// is clamped to `[0, 1024]`, and a NaN rate disables mutation.
+    fn mutate_with_rate(&mut self, rate: f64, rng: &mut dyn RngCore) {
+        const MAX_MUTATIONS: f64 = 1024.0;
+
+        let rate = if rate.is_nan() {
+            0.0
+        } else {
+            rate.clamp(0.0, MAX_MUTATIONS)
+        };
+        let whole = rate.trunc();
+        for _ in 0..whole as usize {
+            self.mutate(rng);
+        }
+
+        let fraction = rate - whole;
+        if fraction > 0.0 && rng.gen_bool(fraction) {
+            self.mutate(rng);
+        }
+    }
+
     /// Combine the candidate with another one to create an offspring.
     fn crossover(&self, other: &Self, rng: &mut dyn RngCore) -> Self;
 }
@@ -34,6 +58,13 @@ pub enum OptimizationError {
     /// Returned when the tournament size is zero.
     #[error("tournament size must be greater than zero")]
     InvalidTournamentSize,
+    /// Returned when the mutation rate or diversity boost multiplier is negative or not
+    /// finite.
+    #[error("mutation rate and diversity boost multiplier must be finite and non-negative")]
+    InvalidMutationRate,
+    /// Returned when early stopping is configured with zero patience.
+    #[error("early stopping patience must be greater than zero")]
+    InvalidPatience,
     /// Returned when evaluating a candidate fails.
     #[error("candidate evaluation failed: {0}")]
     EvaluationFailed(String),
@@ -68,10 +99,34 @@ where
     pub best_fitness: f64,
     /// Average fitness across the generation.
     pub average_fitness: f64,
+    /// Standard deviation of the finite fitness scores in the generation. Values near zero
+    /// indicate the population has converged.
+    pub fitness_std: f64,
+    /// Number of distinct finite fitness scores in the generation.
+    pub unique_fitness_count: usize,
     /// Metrics produced by the best candidate of the generation.
     pub best_metrics: M,
 }
 
+/// Stops a run once the best fitness stops improving.
+#[derive(Debug, Clone, Copy)]
+pub struct EarlyStopping {
+    /// Number of consecutive generations without improvement tolerated before stopping.
+    /// Must be greater than zero.
+    pub patience: usize,
+    /// Minimum increase of the best fitness that counts as an improvement.
+    pub min_delta: f64,
+}
+
+/// Raises the mutation rate while the population lacks fitness diversity.
+#[derive(Debug, Clone, Copy)]
+pub struct DiversityBoost {
+    /// Fitness standard deviation below which the previous generation counts as converged.
+    pub std_threshold: f64,
+    /// Factor applied to the mutation rate while the population is converged.
+    pub multiplier: f64,
+}
+
 /// Configuration for the genetic optimizer.
 #[derive(Debug, Clone, Copy)]
 pub struct GeneticOptimizerConfig {
@@ -83,6 +138,16 @@ pub struct GeneticOptimizerConfig {
     pub generations: usize,
     /// Tournament size used for parent selection.
     pub tournament_size: usize,
+    /// Optional stagnation criterion that ends the run before `generations` is reached.
+    pub early_stopping: Option<EarlyStopping>,
+    /// Expected number of mutations applied to each offspring.
+    pub mutation_rate: f64,
+    /// Optional adaptive increase of the mutation rate when diversity collapses.
+    pub diversity_boost: Option<DiversityBoost>,
+    /// Evaluate candidates on a rayon thread pool. Requires the `parallel` feature; without
+    /// it this flag is ignored and candidates are evaluated serially. Genetic operators
+    /// always run on the calling thread.
+    pub parallel_evaluation: bool,
 }
 
 impl Default for GeneticOptimizerConfig {
@@ -92,6 +157,10 @@ impl Default for GeneticOptimizerConfig {
             elitism: 2,
             generations: 20,
             tournament_size: 3,
+            early_stopping: None,
+            mutation_rate: 1.0,
+            diversity_boost: None,
+            parallel_evaluation: false,
         }
     }
 }
@@ -183,6 +252,29 @@ where
         &self,
         rng: &mut R,
     ) -> Result<OptimizationResult<G, E::Metrics>, OptimizationError>
+    where
+        R: RngCore,
+    {
+        self.run_with_seeds(rng, Vec::new())
+    }
+
+    /// Execute the optimization run with an internally seeded RNG so that repeated calls
+    /// with the same `seed` produce identical results.
+    pub fn run_seeded(
+        &self,
+        seed: u64,
+    ) -> Result<OptimizationResult<G, E::Metrics>, OptimizationError> {
+        self.run(&mut StdRng::seed_from_u64(seed))
+    }
+
+    /// Execute the optimization run with `seeds` placed at the front of the initial
+    /// population. Seeds beyond `population_size` are dropped and the remaining slots are
+    /// filled with random candidates.
+    pub fn run_with_seeds<R>(
+        &self,
+        rng: &mut R,
+        seeds: Vec<G>,
+    ) -> Result<OptimizationResult<G, E::Metrics>, OptimizationError>
     where
         R: RngCore,
     {
@@ -198,9 +290,29 @@ where
             return Err(OptimizationError::InvalidTournamentSize);
         }
 
-        let mut population: Vec<Individual<G, E::Metrics>> = (0..self.config.population_size)
-            .map(|_| Individual::unevaluated(G::random(rng)))
+        let is_valid_rate = |rate: f64| rate.is_finite() && rate >= 0.0;
+        if !is_valid_rate(self.config.mutation_rate)
+            || self
+                .config
+                .diversity_boost
+                .is_some_and(|boost| !is_valid_rate(boost.multiplier))
+        {
+            return Err(OptimizationError::InvalidMutationRate);
+        }
+
+        if matches!(self.config.early_stopping, Some(early_stopping) if early_stopping.patience == 0)
+        {
+            return Err(OptimizationError::InvalidPatience);
+        }
+
+        let mut population: Vec<Individual<G, E::Metrics>> = seeds
+            .into_iter()
+            .take(self.config.population_size)
+            .map(Individual::unevaluated)
             .collect();
+        while population.len() < self.config.population_size {
+            population.push(Individual::unevaluated(G::random(rng)));
+        }
 
         let mut generation_summaries = Vec::with_capacity(self.config.generations + 1);
 
@@ -208,11 +320,21 @@ where
         population.sort_by(|a, b| b.fitness.total_cmp(&a.fitness));
         generation_summaries.push(Self::summarize_generation(0, &population));
 
+        let mut best_fitness = population[0].fitness;
+        let mut stagnant_generations = 0usize;
+
         for generation in 1..=self.config.generations {
             let mut next_population: Vec<Individual<G, E::Metrics>> =
                 Vec::with_capacity(self.config.population_size);
             next_population.extend(population.iter().take(self.config.elitism).cloned());
 
+            let mutation_rate = match (self.config.diversity_boost, generation_summaries.last()) {
+                (Some(boost), Some(previous)) if previous.fitness_std < boost.std_threshold => {
+                    self.config.mutation_rate * boost.multiplier
+                }
+                _ => self.config.mutation_rate,
+            };
+
             while next_population.len() < self.config.population_size {
                 let parent_a =
                     Self::tournament_select(&population, self.config.tournament_size, rng);
@@ -220,7 +342,7 @@ where
                     Self::tournament_select(&population, self.config.tournament_size, rng);
 
                 let mut child_genome = parent_a.genome.crossover(&parent_b.genome, rng);
-                child_genome.mutate(rng);
+                child_genome.mutate_with_rate(mutation_rate, rng);
                 next_population.push(Individual::unevaluated(child_genome));
             }
 
@@ -228,6 +350,19 @@ where
             self.evaluate_population(&mut population)?;
             population.sort_by(|a, b| b.fitness.total_cmp(&a.fitness));
             generation_summaries.push(Self::summarize_generation(generation, &population));
+
+            if let Some(early_stopping) = self.config.early_stopping {
+                if population[0].fitness > best_fitness + early_stopping.min_delta {
+                    best_fitness = population[0].fitness;
+                    stagnant_generations = 0;
+                } else {
+                    stagnant_generations += 1;
+                }
+
+                if stagnant_generations >= early_stopping.patience {
+                    break;
+                }
+            }
         }
 
         let best = population
@@ -249,23 +384,37 @@ where
         &self,
         population: &mut [Individual<G, E::Metrics>],
     ) -> Result<(), OptimizationError> {
-        for individual in population.iter_mut() {
-            if individual.metrics.is_some() {
-                continue;
-            }
+        #[cfg(feature = "parallel")]
+        if self.config.parallel_evaluation {
+            use rayon::prelude::*;
+
+            return population
+                .par_iter_mut()
+                .filter(|individual| individual.metrics.is_none())
+                .try_for_each(|individual| self.evaluate_individual(individual));
+        }
 
-            let outcome = self
-                .evaluator
-                .evaluate(&individual.genome)
-                .map_err(|err| OptimizationError::EvaluationFailed(err.to_string()))?;
+        population
+            .iter_mut()
+            .filter(|individual| individual.metrics.is_none())
+            .try_for_each(|individual| self.evaluate_individual(individual))
+    }
 
-            individual.fitness = if outcome.fitness.is_finite() {
-                outcome.fitness
-            } else {
-                f64::NEG_INFINITY
-            };
-            individual.metrics = Some(outcome.metrics);
-        }
+    fn evaluate_individual(
+        &self,
+        individual: &mut Individual<G, E::Metrics>,
+    ) -> Result<(), OptimizationError> {
+        let outcome = self
+            .evaluator
+            .evaluate(&individual.genome)
+            .map_err(|err| OptimizationError::EvaluationFailed(err.to_string()))?;
+
+        individual.fitness = if outcome.fitness.is_finite() {
+            outcome.fitness
+        } else {
+            f64::NEG_INFINITY
+        };
+        individual.metrics = Some(outcome.metrics);
 
         Ok(())
     }
@@ -297,8 +446,7 @@ where
         index: usize,
         population: &[Individual<G, E::Metrics>],
     ) -> GenerationSummary<E::Metrics> {
-        let mut total = 0.0;
-        let mut count = 0usize;
+        let mut finite_fitness = Vec::with_capacity(population.len());
         let mut best: Option<&Individual<G, E::Metrics>> = None;
 
         for individual in population {
@@ -311,23 +459,34 @@ where
             }
 
             if individual.fitness.is_finite() {
-                total += individual.fitness;
-                count += 1;
+                finite_fitness.push(individual.fitness);
             }
         }
 
-        let average = if count > 0 {
-            total / count as f64
+        let count = finite_fitness.len();
+        let (average, fitness_std) = if count > 0 {
+            let mean = finite_fitness.iter().sum::<f64>() / count as f64;
+            let variance = finite_fitness
+                .iter()
+                .map(|fitness| (fitness - mean).powi(2))
+                .sum::<f64>()
+                / count as f64;
+            (mean, variance.sqrt())
         } else {
-            f64::NEG_INFINITY
+            (f64::NEG_INFINITY, 0.0)
         };
 
+        finite_fitness.sort_by(f64::total_cmp);
+        finite_fitness.dedup();
+
         let best = best.expect("population must contain at least one individual");
 
         GenerationSummary {
             index,
             best_fitness: best.fitness,
             average_fitness: average,
+            fitness_std,
+            unique_fitness_count: finite_fitness.len(),
             best_metrics: best
                 .metrics
                 .clone()
@@ -3,7 +3,7 @@ use std::collections::HashMap;
 use chrono::{DateTime, FixedOffset, Utc};
 use thiserror::Error;
 
-use crate::unified_data::{OrderRequest, OrderSide, OrderType, Position};
+use crate::unified_data::{OrderRequest, OrderSide, OrderType, Position, RejectionReason};
 
 /// Configuration values used by the [`RiskManager`].
 #[derive(Debug, Clone)]
@@ -11,6 +11,14 @@ pub struct RiskConfig {
     pub max_position_size_pct: f64,
     pub stop_loss_pct: f64,
     pub take_profit_pct: f64,
+    /// Number of bars after a stop-loss during which new entries in that symbol are refused.
+    pub cooldown_bars: usize,
+    /// Maximum number of symbols that may hold an open position at the same time.
+    pub max_concurrent_positions: Option<usize>,
+    /// Fraction of the initial portfolio value below which trading halts as ruined.
+    pub min_equity_fraction: Option<f64>,
+    /// Upper bound applied to Kelly fractions returned by [`RiskManager::kelly_fraction`].
+    pub max_kelly: f64,
 }
 
 impl Default for RiskConfig {
@@ -19,6 +27,10 @@ impl Default for RiskConfig {
             max_position_size_pct: 0.1,
             stop_loss_pct: 0.05,
             take_profit_pct: 0.1,
+            cooldown_bars: 0,
+            max_concurrent_positions: None,
+            min_equity_fraction: None,
+            max_kelly: 0.25,
         }
     }
 }
@@ -32,6 +44,32 @@ pub enum RiskError {
     /// Returned when trading is halted by the emergency stop flag.
     #[error("trading is halted by the emergency stop toggle")]
     TradingHalted,
+    /// Returned when an entry is attempted while the symbol is cooling down after a stop-loss.
+    #[error("{symbol} is cooling down after a stop-loss for {remaining_bars} more bar(s)")]
+    CooldownActive {
+        symbol: String,
+        remaining_bars: usize,
+    },
+    /// Returned when opening a new position would exceed the concurrent position cap.
+    #[error("already holding {open} positions, the configured maximum is {limit}")]
+    MaxPositionsReached { open: usize, limit: usize },
+    /// Returned when the portfolio value falls below the configured equity floor.
+    #[error("portfolio value {value:.2} fell below the equity floor of {floor:.2}")]
+    EquityFloorBreached { value: f64, floor: f64 },
+}
+
+impl From<&RiskError> for RejectionReason {
+    fn from(error: &RiskError) -> Self {
+        match error {
+            RiskError::PositionSizeExceeded { .. } => RejectionReason::RiskLimit,
+            RiskError::TradingHalted | RiskError::EquityFloorBreached { .. } => {
+                RejectionReason::EmergencyStop
+            }
+            RiskError::CooldownActive { .. } | RiskError::MaxPositionsReached { .. } => {
+                RejectionReason::RiskLimit
+            }
+        }
+    }
 }
 
 /// Convenience result type for risk management operations.
@@ -88,10 +126,13 @@ impl RiskOrder {
 #[derive(Debug, Clone)]
 pub struct RiskManager {
     config: RiskConfig,
+    initial_portfolio_value: f64,
     portfolio_value: f64,
     stop_losses: Vec<RiskOrder>,
     take_profits: Vec<RiskOrder>,
+    cooldowns: HashMap<String, usize>,
     emergency_stop: bool,
+    ruined: bool,
 }
 
 impl RiskManager {
@@ -99,10 +140,13 @@ impl RiskManager {
     pub fn new(config: RiskConfig, portfolio_value: f64) -> Self {
         Self {
             config,
+            initial_portfolio_value: portfolio_value,
             portfolio_value,
             stop_losses: Vec::new(),
             take_profits: Vec::new(),
+            cooldowns: HashMap::new(),
             emergency_stop: false,
+            ruined: false,
         }
     }
 
@@ -111,28 +155,83 @@ impl RiskManager {
         &self.config
     }
 
-    /// Update the tracked portfolio value. The current implementation simply records
-    /// the latest value so that position size checks have an up-to-date notion of the
-    /// account size.
+    /// Update the tracked portfolio value so that position size checks have an up-to-date
+    /// notion of the account size.
+    ///
+    /// When `min_equity_fraction` is configured and the value drops below that fraction of
+    /// the initial portfolio value, the account is marked as ruined and
+    /// [`RiskError::EquityFloorBreached`] is returned so the caller can flatten its positions.
+    /// A ruined account only accepts reduce-only orders from then on.
     pub fn update_portfolio_value(
         &mut self,
         new_value: f64,
         _realized_pnl_delta: f64,
     ) -> Result<()> {
         self.portfolio_value = new_value.max(0.0);
+
+        if let Some(floor) = self.equity_floor() {
+            if self.portfolio_value < floor {
+                self.ruined = true;
+                return Err(RiskError::EquityFloorBreached {
+                    value: self.portfolio_value,
+                    floor,
+                });
+            }
+        }
+
         Ok(())
     }
 
-    /// Validate an order against simple position size limits and the emergency stop flag.
+    /// Whether the portfolio value has breached the configured equity floor.
+    pub fn is_ruined(&self) -> bool {
+        self.ruined
+    }
+
+    fn equity_floor(&self) -> Option<f64> {
+        self.config
+            .min_equity_fraction
+            .map(|fraction| self.initial_portfolio_value * fraction)
+    }
+
+    /// Validate an order against simple position size limits, the concurrent position cap, the
+    /// equity floor and the emergency stop flag.
     pub fn validate_order(
         &self,
         order: &OrderRequest,
-        _positions: &HashMap<String, Position>,
+        positions: &HashMap<String, Position>,
     ) -> Result<()> {
         if self.emergency_stop {
             return Err(RiskError::TradingHalted);
         }
 
+        if !order.reduce_only {
+            if self.ruined {
+                return Err(RiskError::EquityFloorBreached {
+                    value: self.portfolio_value,
+                    floor: self.equity_floor().unwrap_or(0.0),
+                });
+            }
+
+            if let Some(&remaining_bars) = self.cooldowns.get(&order.symbol) {
+                return Err(RiskError::CooldownActive {
+                    symbol: order.symbol.clone(),
+                    remaining_bars,
+                });
+            }
+
+            if let Some(limit) = self.config.max_concurrent_positions {
+                let is_open = |position: &Position| position.size != 0.0;
+                let opens_new_position = !positions.get(&order.symbol).is_some_and(is_open);
+                let open = positions
+                    .values()
+                    .filter(|position| is_open(position))
+                    .count();
+                if opens_new_position && open >= limit {
+                    return Err(RiskError::MaxPositionsReached { open, limit });
+                }
+            }
+        }
+
         if let Some(price) = order.price {
             let notional = price * order.quantity.abs();
             let max_notional = self.config.max_position_size_pct * self.portfolio_value;
@@ -151,6 +250,49 @@ impl RiskManager {
         Ok(())
     }
 
+    /// Size a position so that hitting `stop_price` loses `risk_fraction` of the tracked
+    /// portfolio value.
+    ///
+    /// The stop may sit on either side of the entry, so the same helper sizes longs and
+    /// shorts. The quantity is capped by `max_position_size_pct`, and `0.0` is returned when
+    /// the stop distance is zero or any input is not finite.
+    pub fn size_for_risk(&self, entry_price: f64, stop_price: f64, risk_fraction: f64) -> f64 {
+        let stop_distance = (entry_price - stop_price).abs();
+        let risk_budget = risk_fraction * self.portfolio_value;
+        if stop_distance == 0.0 || !stop_distance.is_finite() || !risk_budget.is_finite() {
+            return 0.0;
+        }
+
+        let quantity = (risk_budget / stop_distance).max(0.0);
+        let max_notional = self.config.max_position_size_pct * self.portfolio_value;
+        if max_notional > 0.0 && entry_price > 0.0 {
+            quantity.min(max_notional / entry_price)
+        } else {
+            quantity
+        }
+    }
+
+    /// Kelly fraction `win_rate - (1 - win_rate) / win_loss_ratio` clamped to
+    /// `[0, max_kelly]`. Strategies without a positive edge get `0.0`.
+    pub fn kelly_fraction(&self, win_rate: f64, win_loss_ratio: f64) -> f64 {
+        if win_loss_ratio <= 0.0 || !win_loss_ratio.is_finite() || !win_rate.is_finite() {
+            return 0.0;
+        }
+
+        let fraction = win_rate - (1.0 - win_rate) / win_loss_ratio;
+        fraction.clamp(0.0, self.config.max_kelly.max(0.0))
+    }
+
+    /// Quantity to buy at `entry_price` when allocating the clamped Kelly fraction of the
+    /// tracked portfolio value.
+    pub fn size_with_kelly(&self, win_rate: f64, win_loss_ratio: f64, entry_price: f64) -> f64 {
+        if entry_price <= 0.0 || !entry_price.is_finite() {
+            return 0.0;
+        }
+
+        self.kelly_fraction(win_rate, win_loss_ratio) * self.portfolio_value / entry_price
+    }
+
     /// Produce a stop-loss order for the supplied position.
     pub fn generate_stop_loss(&self, position: &Position, order_id: &str) -> Option<RiskOrder> {
         if position.size == 0.0 || self.config.stop_loss_pct <= 0.0 {
@@ -259,21 +401,42 @@ impl RiskManager {
             true
         });
 
+        if self.config.cooldown_bars > 0 {
+            for order in triggered.iter().filter(|order| order.is_stop_loss) {
+                self.cooldowns
+                    .insert(order.symbol.clone(), self.config.cooldown_bars);
+            }
+        }
+
         triggered
     }
 
+    /// Advance the bar counter used by stop-loss cooldowns. Call once per processed bar.
+    pub fn advance_bar(&mut self) {
+        self.cooldowns.retain(|_, remaining| {
+            *remaining -= 1;
+            *remaining > 0
+        });
+    }
+
+    /// Number of bars left before new entries in `symbol` are accepted again.
+    pub fn cooldown_remaining(&self, symbol: &str) -> usize {
+        self.cooldowns.get(symbol).copied().unwrap_or(0)
+    }
+
     /// Manually trigger the emergency stop.
     pub fn activate_emergency_stop(&mut self) {
         self.emergency_stop = true;
     }
 
-    /// Clear the emergency stop condition.
+    /// Clear the emergency stop condition. This does not lift the entry block of a ruined
+    /// account.
     pub fn deactivate_emergency_stop(&mut self) {
         self.emergency_stop = false;
     }
 
     /// Check whether trading should be halted.
     pub fn should_stop_trading(&self) -> bool {
-        self.emergency_stop
+        self.emergency_stop || self.ruined
     }
 }
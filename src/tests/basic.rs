@@ -1,7 +1,12 @@
+use std::collections::HashMap;
+
 use chrono::{FixedOffset, Utc};
 
+use crate::risk_manager::{RiskConfig, RiskError, RiskManager};
 use crate::unified_data::FundingPayment;
-use crate::unified_data::{OrderRequest, OrderSide, OrderType, Position, TimeInForce};
+use crate::unified_data::{
+    OrderRequest, OrderResult, OrderSide, OrderType, Position, RejectionReason, TimeInForce,
+};
 
 #[test]
 fn position_updates_total_pnl_after_price_change_and_funding() {
@@ -37,6 +42,7 @@ fn funding_payment_struct_is_constructible() {
     let timestamp = Utc::now().with_timezone(&tz);
 
     let payment = FundingPayment {
+        symbol: "BTC".to_string(),
         timestamp,
         position_size: 0.75,
         funding_rate: 0.0001,
@@ -47,3 +53,236 @@ fn funding_payment_struct_is_constructible() {
     assert!(payment.payment_amount.is_finite());
     assert_eq!(payment.position_size, 0.75);
 }
+
+#[test]
+fn rejected_order_result_carries_structured_reason() {
+    let order = OrderRequest::limit("BTC", OrderSide::Buy, 0.5, 30000.0);
+    let reasons = [
+        RejectionReason::RiskLimit,
+        RejectionReason::InsufficientBalance,
+        RejectionReason::EmergencyStop,
+        RejectionReason::Validation,
+        RejectionReason::ApiError,
+        RejectionReason::StaleData,
+    ];
+
+    for reason in reasons {
+        let result = OrderResult::rejected(&order, reason, "detail");
+
+        assert!(result.is_rejected());
+        assert_eq!(result.rejection_reason, Some(reason));
+        assert_eq!(result.error.as_deref(), Some("detail"));
+        assert_eq!(result.quantity, 0.0);
+    }
+
+    assert!(!OrderResult::new("1", "BTC", OrderSide::Buy, 0.5, 30000.0).is_rejected());
+}
+
+#[test]
+fn risk_errors_map_to_rejection_reasons() {
+    let mut manager = RiskManager::new(RiskConfig::default(), 10_000.0);
+    let positions = HashMap::new();
+
+    let oversized = OrderRequest::limit("BTC", OrderSide::Buy, 1.0, 30000.0);
+    let error = manager
+        .validate_order(&oversized, &positions)
+        .expect_err("order exceeds position limit");
+    assert_eq!(RejectionReason::from(&error), RejectionReason::RiskLimit);
+
+    manager.activate_emergency_stop();
+    let error = manager
+        .validate_order(&oversized, &positions)
+        .expect_err("trading is halted");
+    assert_eq!(
+        RejectionReason::from(&error),
+        RejectionReason::EmergencyStop
+    );
+}
+
+#[test]
+fn entries_are_refused_during_stop_loss_cooldown() {
+    let config = RiskConfig {
+        cooldown_bars: 2,
+        ..RiskConfig::default()
+    };
+    let mut manager = RiskManager::new(config, 100_000.0);
+    let positions = HashMap::new();
+
+    let tz = FixedOffset::east_opt(0).expect("valid offset");
+    let position = Position::new("BTC", 0.1, 100.0, 100.0, Utc::now().with_timezone(&tz));
+    let stop = manager
+        .generate_stop_loss(&position, "entry-1")
+        .expect("stop-loss for open position");
+    manager.register_stop_loss(stop);
+
+    let prices = HashMap::from([("BTC".to_string(), 90.0)]);
+    assert_eq!(manager.check_risk_orders(&prices).len(), 1);
+
+    let entry = OrderRequest::limit("BTC", OrderSide::Buy, 0.1, 90.0);
+    assert!(matches!(
+        manager.validate_order(&entry, &positions),
+        Err(RiskError::CooldownActive {
+            remaining_bars: 2,
+            ..
+        })
+    ));
+
+    let mut exit = OrderRequest::limit("BTC", OrderSide::Sell, 0.1, 90.0);
+    exit.reduce_only = true;
+    assert!(manager.validate_order(&exit, &positions).is_ok());
+
+    manager.advance_bar();
+    assert_eq!(manager.cooldown_remaining("BTC"), 1);
+    assert!(manager.validate_order(&entry, &positions).is_err());
+
+    manager.advance_bar();
+    assert_eq!(manager.cooldown_remaining("BTC"), 0);
+    assert!(manager.validate_order(&entry, &positions).is_ok());
+}
+
+#[test]
+fn new_positions_are_refused_beyond_concurrent_cap() {
+    let config = RiskConfig {
+        max_concurrent_positions: Some(2),
+        ..RiskConfig::default()
+    };
+    let manager = RiskManager::new(config, 100_000.0);
+
+    let tz = FixedOffset::east_opt(0).expect("valid offset");
+    let timestamp = Utc::now().with_timezone(&tz);
+    let mut positions = HashMap::new();
+
+    for symbol in ["BTC", "ETH"] {
+        let order = OrderRequest::limit(symbol, OrderSide::Buy, 0.1, 100.0);
+        assert!(manager.validate_order(&order, &positions).is_ok());
+        positions.insert(
+            symbol.to_string(),
+            Position::new(symbol, 0.1, 100.0, 100.0, timestamp),
+        );
+    }
+
+    let third = OrderRequest::limit("SOL", OrderSide::Buy, 0.1, 100.0);
+    assert!(matches!(
+        manager.validate_order(&third, &positions),
+        Err(RiskError::MaxPositionsReached { open: 2, limit: 2 })
+    ));
+
+    let mut reduce = OrderRequest::limit("SOL", OrderSide::Sell, 0.1, 100.0);
+    reduce.reduce_only = true;
+    assert!(manager.validate_order(&reduce, &positions).is_ok());
+
+    let add_to_existing = OrderRequest::limit("BTC", OrderSide::Buy, 0.1, 100.0);
+    assert!(manager.validate_order(&add_to_existing, &positions).is_ok());
+}
+
+#[test]
+fn risk_based_size_shrinks_as_stop_widens() {
+    let config = RiskConfig {
+        max_position_size_pct: 1.0,
+        ..RiskConfig::default()
+    };
+    let manager = RiskManager::new(config, 10_000.0);
+
+    let tight = manager.size_for_risk(100.0, 98.0, 0.01);
+    let wide = manager.size_for_risk(100.0, 95.0, 0.01);
+
+    assert!((tight - 50.0).abs() < 1e-9);
+    assert!((wide - 20.0).abs() < 1e-9);
+    assert!(wide < tight);
+    assert_eq!(manager.size_for_risk(100.0, 100.0, 0.01), 0.0);
+}
+
+#[test]
+fn risk_based_size_keeps_dollar_risk_constant_for_longs_and_shorts() {
+    let config = RiskConfig {
+        max_position_size_pct: 1.0,
+        ..RiskConfig::default()
+    };
+    let manager = RiskManager::new(config, 20_000.0);
+    let risk_budget = 0.005 * 20_000.0;
+
+    let long = manager.size_for_risk(50.0, 48.0, 0.005);
+    let short = manager.size_for_risk(50.0, 55.0, 0.005);
+
+    assert!((long * (50.0 - 48.0) - risk_budget).abs() < 1e-9);
+    assert!((short * (55.0 - 50.0) - risk_budget).abs() < 1e-9);
+
+    let capped = RiskManager::new(RiskConfig::default(), 20_000.0);
+    let quantity = capped.size_for_risk(50.0, 49.9, 0.01);
+    assert!((quantity * 50.0 - 0.1 * 20_000.0).abs() < 1e-9);
+}
+
+#[test]
+fn trading_halts_when_equity_breaches_floor() {
+    let config = RiskConfig {
+        min_equity_fraction: Some(0.1),
+        ..RiskConfig::default()
+    };
+    let mut manager = RiskManager::new(config, 10_000.0);
+    let positions = HashMap::new();
+    let order = OrderRequest::limit("BTC", OrderSide::Buy, 0.01, 100.0);
+
+    assert!(manager.update_portfolio_value(5_000.0, -5_000.0).is_ok());
+    assert!(!manager.is_ruined());
+    assert!(manager.validate_order(&order, &positions).is_ok());
+
+    assert!(matches!(
+        manager.update_portfolio_value(900.0, -4_100.0),
+        Err(RiskError::EquityFloorBreached { .. })
+    ));
+    assert!(manager.is_ruined());
+    assert!(manager.should_stop_trading());
+    assert!(matches!(
+        manager.validate_order(&order, &positions),
+        Err(RiskError::EquityFloorBreached { .. })
+    ));
+
+    let mut exit = OrderRequest::limit("BTC", OrderSide::Sell, 0.01, 100.0);
+    exit.reduce_only = true;
+    assert!(manager.validate_order(&exit, &positions).is_ok());
+
+    manager.deactivate_emergency_stop();
+    assert!(manager.should_stop_trading());
+    assert!(matches!(
+        manager.validate_order(&order, &positions),
+        Err(RiskError::EquityFloorBreached { .. })
+    ));
+}
+
+#[test]
+fn funding_payments_derive_amount_and_sum() {
+    let tz = FixedOffset::east_opt(0).expect("valid offset");
+    let timestamp = Utc::now().with_timezone(&tz);
+
+    let payments = [
+        FundingPayment::new("BTC", timestamp, 2.0, 0.0001, 25_000.0),
+        FundingPayment::new("BTC", timestamp, 2.0, -0.0002, 25_000.0),
+        FundingPayment::new("BTC", timestamp, -1.0, 0.0001, 25_000.0),
+    ];
+
+    assert!((payments[0].payment_amount + 5.0).abs() < 1e-9);
+    assert!((payments[1].payment_amount - 10.0).abs() < 1e-9);
+    assert!((payments[2].payment_amount - 2.5).abs() < 1e-9);
+
+    let total: f64 = payments.iter().map(|payment| payment.payment_amount).sum();
+    assert!((total - 7.5).abs() < 1e-9);
+    assert!(payments.iter().all(|payment| payment.symbol == "BTC"));
+}
+
+#[test]
+fn kelly_fraction_is_clamped_to_configured_range() {
+    let config = RiskConfig {
+        max_kelly: 0.5,
+        ..RiskConfig::default()
+    };
+    let manager = RiskManager::new(config, 10_000.0);
+
+    let positive_edge = manager.kelly_fraction(0.55, 1.5);
+    assert!((positive_edge - (0.55 - 0.45 / 1.5)).abs() < 1e-12);
+    assert!((manager.size_with_kelly(0.55, 1.5, 100.0) - positive_edge * 100.0).abs() < 1e-9);
+
+    assert_eq!(manager.kelly_fraction(0.5, 1.0), 0.0);
+    assert_eq!(manager.kelly_fraction(0.3, 1.0), 0.0);
+
+    assert_eq!(manager.kelly_fraction(0.9, 4.0), 0.5);
+}
@@ -0,0 +1,309 @@
+use std::convert::Infallible;
+
+use rand::rngs::StdRng;
+use rand::{Rng, RngCore, SeedableRng};
+
+use crate::optimization::{
+    DiversityBoost, EarlyStopping, GeneticOptimizer, GeneticOptimizerConfig, Genome,
+    OptimizationError, OptimizationOutcome,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+struct Point {
+    x: f64,
+}
+
+impl Genome for Point {
+    fn random(rng: &mut dyn RngCore) -> Self {
+        Self {
+            x: rng.gen_range(-10.0..=10.0),
+        }
+    }
+
+    fn mutate(&mut self, rng: &mut dyn RngCore) {
+        self.x = (self.x + rng.gen_range(-0.5..=0.5)).clamp(-10.0, 10.0);
+    }
+
+    fn crossover(&self, other: &Self, _rng: &mut dyn RngCore) -> Self {
+        Self {
+            x: (self.x + other.x) / 2.0,
+        }
+    }
+}
+
+fn constant_fitness(_candidate: &Point) -> Result<OptimizationOutcome<()>, Infallible> {
+    Ok(OptimizationOutcome {
+        fitness: 1.0,
+        metrics: (),
+    })
+}
+
+#[test]
+fn run_stops_early_when_fitness_stagnates() {
+    let config = GeneticOptimizerConfig {
+        population_size: 8,
+        elitism: 1,
+        generations: 50,
+        tournament_size: 2,
+        early_stopping: Some(EarlyStopping {
+            patience: 3,
+            min_delta: 1e-9,
+        }),
+        ..GeneticOptimizerConfig::default()
+    };
+    let optimizer = GeneticOptimizer::new(config, constant_fitness);
+    let mut rng = StdRng::seed_from_u64(7);
+
+    let result = optimizer.run(&mut rng).expect("optimization succeeds");
+
+    assert_eq!(result.generations.len(), 4);
+    assert_eq!(
+        result.generations.last().map(|summary| summary.index),
+        Some(3)
+    );
+    assert_eq!(result.best_fitness, 1.0);
+}
+
+#[test]
+fn zero_patience_is_rejected() {
+    let config = GeneticOptimizerConfig {
+        early_stopping: Some(EarlyStopping {
+            patience: 0,
+            min_delta: 0.0,
+        }),
+        ..GeneticOptimizerConfig::default()
+    };
+
+    let result = GeneticOptimizer::new(config, constant_fitness).run_seeded(1);
+
+    assert!(matches!(result, Err(OptimizationError::InvalidPatience)));
+}
+
+#[test]
+fn seeded_population_keeps_known_good_candidates() {
+    fn peak_at_five(candidate: &Point) -> Result<OptimizationOutcome<()>, Infallible> {
+        Ok(OptimizationOutcome {
+            fitness: -(candidate.x - 5.0).abs(),
+            metrics: (),
+        })
+    }
+
+    let config = GeneticOptimizerConfig {
+        population_size: 4,
+        generations: 0,
+        ..GeneticOptimizerConfig::default()
+    };
+    let optimizer = GeneticOptimizer::new(config, peak_at_five);
+
+    let seeds = (0..6).map(|i| Point { x: 5.0 + i as f64 }).collect();
+    let result = optimizer
+        .run_with_seeds(&mut StdRng::seed_from_u64(3), seeds)
+        .expect("seeded optimization succeeds");
+    assert_eq!(result.best_candidate, Point { x: 5.0 });
+    assert_eq!(result.best_fitness, 0.0);
+
+    let unseeded = optimizer
+        .run(&mut StdRng::seed_from_u64(3))
+        .expect("optimization succeeds");
+    let empty_seeds = optimizer
+        .run_with_seeds(&mut StdRng::seed_from_u64(3), Vec::new())
+        .expect("optimization succeeds");
+    assert_eq!(unseeded.best_candidate, empty_seeds.best_candidate);
+    assert_eq!(unseeded.best_fitness, empty_seeds.best_fitness);
+}
+
+#[test]
+fn generation_summary_reports_fitness_diversity() {
+    fn identity(candidate: &Point) -> Result<OptimizationOutcome<()>, Infallible> {
+        let fitness = if candidate.x < 0.0 {
+            f64::NAN
+        } else {
+            candidate.x
+        };
+        Ok(OptimizationOutcome {
+            fitness,
+            metrics: (),
+        })
+    }
+
+    let config = GeneticOptimizerConfig {
+        population_size: 5,
+        generations: 0,
+        ..GeneticOptimizerConfig::default()
+    };
+    let seeds = [1.0, 2.0, 3.0, 3.0, -1.0]
+        .into_iter()
+        .map(|x| Point { x })
+        .collect();
+
+    let result = GeneticOptimizer::new(config, identity)
+        .run_with_seeds(&mut StdRng::seed_from_u64(5), seeds)
+        .expect("optimization succeeds");
+    let summary = &result.generations[0];
+
+    assert!((summary.average_fitness - 2.25).abs() < 1e-12);
+    assert!((summary.fitness_std - 0.6875_f64.sqrt()).abs() < 1e-12);
+    assert_eq!(summary.unique_fitness_count, 3);
+}
+
+#[test]
+fn run_seeded_is_reproducible() {
+    fn peak_at_two(candidate: &Point) -> Result<OptimizationOutcome<()>, Infallible> {
+        Ok(OptimizationOutcome {
+            fitness: -(candidate.x - 2.0).powi(2),
+            metrics: (),
+        })
+    }
+
+    let optimizer = GeneticOptimizer::new(GeneticOptimizerConfig::default(), peak_at_two);
+
+    let first = optimizer.run_seeded(42).expect("optimization succeeds");
+    let second = optimizer.run_seeded(42).expect("optimization succeeds");
+
+    assert_eq!(first.best_candidate, second.best_candidate);
+    assert_eq!(first.generations.len(), second.generations.len());
+    for (a, b) in first.generations.iter().zip(&second.generations) {
+        assert_eq!(a.best_fitness, b.best_fitness);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Step {
+    x: i32,
+}
+
+impl Genome for Step {
+    fn random(_rng: &mut dyn RngCore) -> Self {
+        Self { x: 0 }
+    }
+
+    fn mutate(&mut self, rng: &mut dyn RngCore) {
+        self.x += if rng.gen_bool(0.5) { 1 } else { -1 };
+    }
+
+    fn crossover(&self, other: &Self, rng: &mut dyn RngCore) -> Self {
+        if rng.gen_bool(0.5) {
+            self.clone()
+        } else {
+            other.clone()
+        }
+    }
+}
+
+#[test]
+fn diversity_boost_escapes_deceptive_local_optimum() {
+    // Fitness falls away from the origin until a distant plateau is reached.
+    fn deceptive(candidate: &Step) -> Result<OptimizationOutcome<()>, Infallible> {
+        let distance = candidate.x.abs();
+        let fitness = if distance >= 8 {
+            100.0
+        } else {
+            -f64::from(distance)
+        };
+        Ok(OptimizationOutcome {
+            fitness,
+            metrics: (),
+        })
+    }
+
+    let fixed = GeneticOptimizerConfig {
+        population_size: 10,
+        elitism: 2,
+        generations: 40,
+        tournament_size: 50,
+        ..GeneticOptimizerConfig::default()
+    };
+    let adaptive = GeneticOptimizerConfig {
+        diversity_boost: Some(DiversityBoost {
+            std_threshold: 1.0,
+            multiplier: 20.0,
+        }),
+        ..fixed
+    };
+
+    let fixed_result = GeneticOptimizer::new(fixed, deceptive)
+        .run_seeded(9)
+        .expect("optimization succeeds");
+    let adaptive_result = GeneticOptimizer::new(adaptive, deceptive)
+        .run_seeded(9)
+        .expect("optimization succeeds");
+
+    assert!(fixed_result.best_fitness <= 0.0);
+    assert_eq!(adaptive_result.best_fitness, 100.0);
+}
+
+#[test]
+fn invalid_mutation_settings_are_rejected() {
+    for mutation_rate in [f64::INFINITY, f64::NAN, -1.0] {
+        let config = GeneticOptimizerConfig {
+            mutation_rate,
+            ..GeneticOptimizerConfig::default()
+        };
+        let result = GeneticOptimizer::new(config, constant_fitness).run_seeded(1);
+        assert!(matches!(
+            result,
+            Err(OptimizationError::InvalidMutationRate)
+        ));
+    }
+
+    let config = GeneticOptimizerConfig {
+        diversity_boost: Some(DiversityBoost {
+            std_threshold: 1.0,
+            multiplier: f64::INFINITY,
+        }),
+        ..GeneticOptimizerConfig::default()
+    };
+    let result = GeneticOptimizer::new(config, constant_fitness).run_seeded(1);
+    assert!(matches!(
+        result,
+        Err(OptimizationError::InvalidMutationRate)
+    ));
+}
+
+#[test]
+fn mutate_with_rate_bounds_huge_rates() {
+    let mut rng = StdRng::seed_from_u64(2);
+    let mut step = Step { x: 0 };
+
+    step.mutate_with_rate(f64::INFINITY, &mut rng);
+    step.mutate_with_rate(f64::MAX, &mut rng);
+    step.mutate_with_rate(f64::NAN, &mut rng);
+
+    assert!(step.x.abs() <= 2048);
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn parallel_evaluation_matches_serial_results() {
+    fn distance_to_three(candidate: &Point) -> Result<OptimizationOutcome<f64>, Infallible> {
+        let distance = (candidate.x - 3.0).abs();
+        Ok(OptimizationOutcome {
+            fitness: -distance,
+            metrics: distance,
+        })
+    }
+
+    let serial = GeneticOptimizerConfig {
+        population_size: 16,
+        generations: 10,
+        ..GeneticOptimizerConfig::default()
+    };
+    let parallel = GeneticOptimizerConfig {
+        parallel_evaluation: true,
+        ..serial
+    };
+
+    let serial_result = GeneticOptimizer::new(serial, distance_to_three)
+        .run(&mut StdRng::seed_from_u64(11))
+        .expect("serial optimization succeeds");
+    let parallel_result = GeneticOptimizer::new(parallel, distance_to_three)
+        .run(&mut StdRng::seed_from_u64(11))
+        .expect("parallel optimization succeeds");
+
+    assert_eq!(serial_result.best_candidate, parallel_result.best_candidate);
+    assert_eq!(serial_result.best_fitness, parallel_result.best_fitness);
+    assert_eq!(
+        serial_result.generations.len(),
+        parallel_result.generations.len()
+    );
+}
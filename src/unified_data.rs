@@ -121,6 +121,23 @@ impl OrderRequest {
     }
 }
 
+/// Structured reason an order was rejected before or during execution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RejectionReason {
+    /// The order breached a risk limit such as the maximum position size.
+    RiskLimit,
+    /// The account did not have enough balance or margin.
+    InsufficientBalance,
+    /// Trading was halted by the emergency stop.
+    EmergencyStop,
+    /// The order itself was malformed or used unsupported parameters.
+    Validation,
+    /// The exchange API returned an error.
+    ApiError,
+    /// The market data used to price the order was too old.
+    StaleData,
+}
+
 /// Outcome of an order execution.
 #[derive(Debug, Clone, PartialEq)]
 pub struct OrderResult {
@@ -130,6 +147,10 @@ pub struct OrderResult {
     pub quantity: f64,
     pub price: f64,
     pub timestamp: DateTime<FixedOffset>,
+    /// Reason the order was rejected, if it was.
+    pub rejection_reason: Option<RejectionReason>,
+    /// Human readable detail accompanying a rejection.
+    pub error: Option<String>,
 }
 
 impl OrderResult {
@@ -141,6 +162,27 @@ impl OrderResult {
             quantity,
             price,
             timestamp: Utc::now().with_timezone(&FixedOffset::east_opt(0).unwrap()),
+            rejection_reason: None,
+            error: None,
+        }
+    }
+
+    /// Build a result describing an order that was rejected without being filled.
+    pub fn rejected(order: &OrderRequest, reason: RejectionReason, error: &str) -> Self {
+        Self {
+            order_id: order.client_order_id.clone().unwrap_or_default(),
+            symbol: order.symbol.clone(),
+            side: order.side,
+            quantity: 0.0,
+            price: order.price.unwrap_or(0.0),
+            timestamp: Utc::now().with_timezone(&FixedOffset::east_opt(0).unwrap()),
+            rejection_reason: Some(reason),
+            error: Some(error.to_string()),
         }
     }
+
+    /// Whether the order was rejected.
+    pub fn is_rejected(&self) -> bool {
+        self.rejection_reason.is_some()
+    }
 }